@@ -1,10 +1,17 @@
+mod sse;
 mod supported_countries;
 
 use anyhow::{anyhow, Result};
-use futures::{io::BufReader, stream::BoxStream, AsyncBufReadExt, AsyncReadExt, Stream, StreamExt};
+use futures::{
+    channel::oneshot, future::BoxFuture, io::BufReader, stream::BoxStream, AsyncReadExt, Stream,
+    StreamExt,
+};
 use http_client::{AsyncBody, HttpClient, Method, Request as HttpRequest};
 use isahc::config::Configurable;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 use strum::EnumIter;
 
@@ -91,12 +98,123 @@ impl Model {
     }
 }
 
-pub async fn complete(
+/// Configures how [`complete`] and [`stream_completion`] retry a request
+/// after a retryable API error (see [`ApiErrorKind::is_retryable`]).
+#[derive(Debug, Clone, Copy)]
+pub struct RetryConfig {
+    /// Total number of attempts, including the first one. `1` disables retries.
+    pub max_attempts: usize,
+    /// Base delay for exponential backoff; doubled on every retry unless the
+    /// server sends a `retry-after` header, which takes precedence.
+    pub base_delay: Duration,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_secs(1),
+        }
+    }
+}
+
+/// An error returned while issuing a request. `Api` is a decoded Anthropic
+/// error envelope, classifiable via [`ApiError::kind`]. `Http` is a non-2xx
+/// response whose body didn't decode as that envelope (a gateway/proxy
+/// error page, a truncated body, …) but whose status code still tells us
+/// whether retrying is worthwhile. `Other` covers everything else
+/// (transport failures, serialization, a non-error success body, …).
+enum RequestError {
+    Api {
+        error: ApiError,
+        retry_after: Option<Duration>,
+    },
+    Http {
+        retry_after: Option<Duration>,
+        error: anyhow::Error,
+    },
+    Other(anyhow::Error),
+}
+
+impl From<anyhow::Error> for RequestError {
+    fn from(error: anyhow::Error) -> Self {
+        Self::Other(error)
+    }
+}
+
+fn exponential_backoff(base_delay: Duration, attempt: usize) -> Duration {
+    base_delay.saturating_mul(1u32 << attempt.saturating_sub(1).min(16) as u32)
+}
+
+fn retry_after_header(response: &http_client::Response<AsyncBody>) -> Option<Duration> {
+    response
+        .headers()
+        .get("retry-after")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse::<u64>().ok())
+        .map(Duration::from_secs)
+}
+
+/// Whether a non-2xx status is worth retrying when its body didn't decode
+/// as an Anthropic error envelope: any 5xx (the "api_error" case the body
+/// would otherwise have named) or 429 (rate limiting from a fronting proxy
+/// that doesn't speak Anthropic's JSON error format).
+fn is_retryable_status(status: http_client::StatusCode) -> bool {
+    status.is_server_error() || status.as_u16() == 429
+}
+
+async fn decode_error_response(
+    response: &mut http_client::Response<AsyncBody>,
+) -> Result<RequestError> {
+    let retry_after = retry_after_header(response);
+    let status = response.status();
+    let mut body = Vec::new();
+    response.body_mut().read_to_end(&mut body).await?;
+    let body_str = String::from_utf8_lossy(&body);
+    match serde_json::from_str::<Event>(&body_str) {
+        Ok(Event::Error { error }) => Ok(RequestError::Api { error, retry_after }),
+        _ => {
+            let error = anyhow!("Failed to connect to API: {} {}", status, body_str);
+            if is_retryable_status(status) {
+                Ok(RequestError::Http { retry_after, error })
+            } else {
+                Ok(RequestError::Other(error))
+            }
+        }
+    }
+}
+
+/// Decides what to do with a failed request attempt: `Ok(delay)` means wait
+/// `delay` and retry, while `Err` is the terminal error to return to the
+/// caller (either the attempt budget is exhausted or the error isn't
+/// retryable).
+fn retry_decision(
+    error: RequestError,
+    attempt: usize,
+    retry_config: &RetryConfig,
+) -> Result<Duration> {
+    let (retryable, retry_after, error) = match error {
+        RequestError::Api { error, retry_after } => {
+            let retryable = error.kind().is_retryable();
+            (retryable, retry_after, api_error_to_err(error))
+        }
+        RequestError::Http {
+            retry_after, error, ..
+        } => (true, retry_after, error),
+        RequestError::Other(error) => (false, None, error),
+    };
+    if !retryable || attempt >= retry_config.max_attempts {
+        return Err(error);
+    }
+    Ok(retry_after.unwrap_or_else(|| exponential_backoff(retry_config.base_delay, attempt)))
+}
+
+async fn try_complete(
     client: &dyn HttpClient,
     api_url: &str,
     api_key: &str,
-    request: Request,
-) -> Result<Response> {
+    request: &Request,
+) -> Result<Response, RequestError> {
     let uri = format!("{api_url}/v1/messages");
     let request_builder = HttpRequest::builder()
         .method(Method::POST)
@@ -106,36 +224,57 @@ pub async fn complete(
         .header("X-Api-Key", api_key)
         .header("Content-Type", "application/json");
 
-    let serialized_request = serde_json::to_string(&request)?;
-    let request = request_builder.body(AsyncBody::from(serialized_request))?;
+    let serialized_request = serde_json::to_string(request)?;
+    let http_request = request_builder.body(AsyncBody::from(serialized_request))?;
 
-    let mut response = client.send(request).await?;
+    let mut response = client.send(http_request).await?;
     if response.status().is_success() {
         let mut body = Vec::new();
         response.body_mut().read_to_end(&mut body).await?;
-        let response_message: Response = serde_json::from_slice(&body)?;
-        Ok(response_message)
+        Ok(serde_json::from_slice(&body)?)
     } else {
-        let mut body = Vec::new();
-        response.body_mut().read_to_end(&mut body).await?;
-        let body_str = std::str::from_utf8(&body)?;
-        Err(anyhow!(
-            "Failed to connect to API: {} {}",
-            response.status(),
-            body_str
-        ))
+        Err(decode_error_response(&mut response).await?)
     }
 }
 
-pub async fn stream_completion(
+pub async fn complete(
     client: &dyn HttpClient,
     api_url: &str,
     api_key: &str,
     request: Request,
+) -> Result<Response> {
+    complete_with_retry(client, api_url, api_key, request, RetryConfig::default()).await
+}
+
+pub async fn complete_with_retry(
+    client: &dyn HttpClient,
+    api_url: &str,
+    api_key: &str,
+    request: Request,
+    retry_config: RetryConfig,
+) -> Result<Response> {
+    let mut attempt = 0;
+    loop {
+        attempt += 1;
+        match try_complete(client, api_url, api_key, &request).await {
+            Ok(response) => return Ok(response),
+            Err(error) => {
+                let delay = retry_decision(error, attempt, &retry_config)?;
+                smol::Timer::after(delay).await;
+            }
+        }
+    }
+}
+
+async fn try_stream_completion(
+    client: &dyn HttpClient,
+    api_url: &str,
+    api_key: &str,
+    request: &Request,
     low_speed_timeout: Option<Duration>,
-) -> Result<BoxStream<'static, Result<Event>>> {
+) -> Result<BoxStream<'static, Result<Event>>, RequestError> {
     let request = StreamingRequest {
-        base: request,
+        base: request.clone(),
         stream: true,
     };
     let uri = format!("{api_url}/v1/messages");
@@ -150,42 +289,67 @@ pub async fn stream_completion(
         request_builder = request_builder.low_speed_timeout(100, low_speed_timeout);
     }
     let serialized_request = serde_json::to_string(&request)?;
-    let request = request_builder.body(AsyncBody::from(serialized_request))?;
+    let http_request = request_builder.body(AsyncBody::from(serialized_request))?;
 
-    let mut response = client.send(request).await?;
+    let mut response = client.send(http_request).await?;
     if response.status().is_success() {
         let reader = BufReader::new(response.into_body());
-        Ok(reader
-            .lines()
-            .filter_map(|line| async move {
-                match line {
-                    Ok(line) => {
-                        let line = line.strip_prefix("data: ")?;
-                        match serde_json::from_str(line) {
-                            Ok(response) => Some(Ok(response)),
+        Ok(sse::decode_stream(reader)
+            .filter_map(|frame| async move {
+                match frame {
+                    Ok(frame) => {
+                        if frame.data.is_empty() {
+                            return None;
+                        }
+                        match serde_json::from_str(&frame.data) {
+                            Ok(event) => Some(Ok(event)),
                             Err(error) => Some(Err(anyhow!(error))),
                         }
                     }
-                    Err(error) => Some(Err(anyhow!(error))),
+                    Err(error) => Some(Err(error)),
                 }
             })
             .boxed())
     } else {
-        let mut body = Vec::new();
-        response.body_mut().read_to_end(&mut body).await?;
+        Err(decode_error_response(&mut response).await?)
+    }
+}
 
-        let body_str = std::str::from_utf8(&body)?;
-
-        match serde_json::from_str::<Event>(body_str) {
-            Ok(Event::Error { error }) => Err(api_error_to_err(error)),
-            Ok(_) => Err(anyhow!(
-                "Unexpected success response while expecting an error: '{body_str}'",
-            )),
-            Err(_) => Err(anyhow!(
-                "Failed to connect to API: {} {}",
-                response.status(),
-                body_str,
-            )),
+pub async fn stream_completion(
+    client: &dyn HttpClient,
+    api_url: &str,
+    api_key: &str,
+    request: Request,
+    low_speed_timeout: Option<Duration>,
+) -> Result<BoxStream<'static, Result<Event>>> {
+    stream_completion_with_retry(
+        client,
+        api_url,
+        api_key,
+        request,
+        low_speed_timeout,
+        RetryConfig::default(),
+    )
+    .await
+}
+
+pub async fn stream_completion_with_retry(
+    client: &dyn HttpClient,
+    api_url: &str,
+    api_key: &str,
+    request: Request,
+    low_speed_timeout: Option<Duration>,
+    retry_config: RetryConfig,
+) -> Result<BoxStream<'static, Result<Event>>> {
+    let mut attempt = 0;
+    loop {
+        attempt += 1;
+        match try_stream_completion(client, api_url, api_key, &request, low_speed_timeout).await {
+            Ok(stream) => return Ok(stream),
+            Err(error) => {
+                let delay = retry_decision(error, attempt, &retry_config)?;
+                smol::Timer::after(delay).await;
+            }
         }
     }
 }
@@ -212,6 +376,254 @@ pub fn extract_text_from_events(
     })
 }
 
+/// Reassembles the `content_block_start` / `content_block_delta` /
+/// `content_block_stop` triples of a tool-use content block into a single
+/// completed [`Content::ToolUse`], concatenating each block's
+/// `input_json_delta` fragments by index and parsing the result once the
+/// block closes.
+pub fn extract_tool_calls_from_events(
+    events: impl Stream<Item = Result<Event>> + Send + 'static,
+) -> impl Stream<Item = Result<Content>> {
+    futures::stream::unfold(
+        (
+            Box::pin(events),
+            HashMap::<usize, (String, String, String)>::new(),
+        ),
+        |(mut events, mut buffers)| async move {
+            loop {
+                match events.next().await {
+                    Some(Ok(Event::ContentBlockStart {
+                        index,
+                        content_block: Content::ToolUse { id, name, .. },
+                    })) => {
+                        buffers.insert(index, (id, name, String::new()));
+                    }
+                    Some(Ok(Event::ContentBlockDelta {
+                        index,
+                        delta: ContentDelta::InputJsonDelta { partial_json },
+                    })) => {
+                        if let Some((_, _, buffer)) = buffers.get_mut(&index) {
+                            buffer.push_str(&partial_json);
+                        }
+                    }
+                    Some(Ok(Event::ContentBlockStop { index })) => {
+                        if let Some((id, name, buffer)) = buffers.remove(&index) {
+                            let input = if buffer.is_empty() {
+                                serde_json::Value::Object(Default::default())
+                            } else {
+                                match serde_json::from_str(&buffer) {
+                                    Ok(input) => input,
+                                    Err(error) => {
+                                        return Some((Err(anyhow!(error)), (events, buffers)))
+                                    }
+                                }
+                            };
+                            return Some((
+                                Ok(Content::ToolUse { id, name, input }),
+                                (events, buffers),
+                            ));
+                        }
+                    }
+                    Some(Ok(Event::Error { error })) => {
+                        return Some((Err(api_error_to_err(error)), (events, buffers)))
+                    }
+                    Some(Ok(_)) => {}
+                    Some(Err(error)) => return Some((Err(error), (events, buffers))),
+                    None => return None,
+                }
+            }
+        },
+    )
+}
+
+/// A callback invoked with a tool's parsed `input` when the model requests
+/// that tool be run; it resolves to the tool's result content blocks (text
+/// and/or images), or an error, which is reported back to the model as a
+/// failed tool result.
+pub type ToolCallback =
+    Box<dyn Fn(serde_json::Value) -> BoxFuture<'static, Result<Vec<Content>>> + Send + Sync>;
+
+/// Drives a multi-step tool-use conversation to completion: streams a turn,
+/// and whenever the model stops with `stop_reason == "tool_use"`, invokes the
+/// matching callback from `tools` for each requested call, appends the
+/// assistant's tool-use message and a user message carrying the tool
+/// results, and streams another turn. Returns once a turn finishes without
+/// requesting any tool use, with the full message history appended to
+/// `request.messages`.
+pub async fn run_conversation(
+    client: &dyn HttpClient,
+    api_url: &str,
+    api_key: &str,
+    mut request: Request,
+    tools: &HashMap<String, ToolCallback>,
+) -> Result<Vec<Message>> {
+    loop {
+        let events = stream_completion(client, api_url, api_key, request.clone(), None).await?;
+        futures::pin_mut!(events);
+
+        let mut text = String::new();
+        let mut tool_use_buffers: HashMap<usize, (String, String, String)> = HashMap::new();
+        let mut tool_uses = Vec::new();
+        let mut stop_reason = None;
+
+        while let Some(event) = events.next().await {
+            match event? {
+                Event::ContentBlockStart {
+                    index,
+                    content_block: Content::ToolUse { id, name, .. },
+                } => {
+                    tool_use_buffers.insert(index, (id, name, String::new()));
+                }
+                Event::ContentBlockDelta { index, delta } => match delta {
+                    ContentDelta::TextDelta { text: delta } => text.push_str(&delta),
+                    ContentDelta::InputJsonDelta { partial_json } => {
+                        if let Some((_, _, buffer)) = tool_use_buffers.get_mut(&index) {
+                            buffer.push_str(&partial_json);
+                        }
+                    }
+                },
+                Event::ContentBlockStop { index } => {
+                    if let Some((id, name, buffer)) = tool_use_buffers.remove(&index) {
+                        let input = if buffer.is_empty() {
+                            serde_json::Value::Object(Default::default())
+                        } else {
+                            serde_json::from_str(&buffer)?
+                        };
+                        tool_uses.push(Content::ToolUse { id, name, input });
+                    }
+                }
+                Event::MessageDelta { delta, .. } => stop_reason = delta.stop_reason,
+                Event::Error { error } => return Err(api_error_to_err(error)),
+                _ => {}
+            }
+        }
+
+        let mut assistant_content = Vec::new();
+        if !text.is_empty() {
+            assistant_content.push(Content::Text { text });
+        }
+        assistant_content.extend(tool_uses.iter().cloned());
+        request.messages.push(Message {
+            role: Role::Assistant,
+            content: assistant_content,
+        });
+
+        if stop_reason.as_deref() != Some("tool_use") || tool_uses.is_empty() {
+            return Ok(request.messages);
+        }
+
+        let mut tool_results = Vec::new();
+        for tool_use in tool_uses {
+            let Content::ToolUse { id, name, input } = tool_use else {
+                unreachable!("tool_uses only ever contains Content::ToolUse")
+            };
+            let result = match tools.get(&name) {
+                Some(callback) => callback(input).await,
+                None => Err(anyhow!("no tool registered with name '{name}'")),
+            };
+            tool_results.push(match result {
+                Ok(content) => Content::ToolResult {
+                    tool_use_id: id,
+                    content,
+                    is_error: None,
+                },
+                Err(error) => Content::ToolResult {
+                    tool_use_id: id,
+                    content: vec![Content::Text {
+                        text: error.to_string(),
+                    }],
+                    is_error: Some(true),
+                },
+            });
+        }
+        request.messages.push(Message {
+            role: Role::User,
+            content: tool_results,
+        });
+    }
+}
+
+/// A running token-usage total, updated as a stream of [`Event`]s produced
+/// by [`track_usage`] or [`final_usage`] is consumed. Shared through an
+/// [`Arc`] and safe to read from another task at any time; the totals only reach their
+/// final value once the stream has been fully drained.
+#[derive(Debug, Default)]
+pub struct UsageTotal(Mutex<Usage>);
+
+impl UsageTotal {
+    pub fn get(&self) -> Usage {
+        *self.0.lock().unwrap()
+    }
+}
+
+fn apply_usage_event(usage: &UsageTotal, event: &Event) {
+    let mut totals = usage.0.lock().unwrap();
+    match event {
+        Event::MessageStart { message } => {
+            if let Some(input_tokens) = message.usage.input_tokens {
+                totals.input_tokens = Some(input_tokens);
+            }
+            if let Some(output_tokens) = message.usage.output_tokens {
+                totals.output_tokens = Some(output_tokens);
+            }
+        }
+        Event::MessageDelta { usage, .. } => {
+            if let Some(input_tokens) = usage.input_tokens {
+                totals.input_tokens = Some(input_tokens);
+            }
+            if let Some(output_tokens) = usage.output_tokens {
+                totals.output_tokens = Some(totals.output_tokens.unwrap_or(0) + output_tokens);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Passes `events` through unchanged while folding a running [`Usage`]
+/// total: the `input_tokens` carried by `message_start` and the
+/// incremental `output_tokens` carried by each `message_delta`. Read the
+/// returned [`UsageTotal`] at any point; it reaches its final value once the
+/// stream is fully drained.
+pub fn track_usage(
+    events: impl Stream<Item = Result<Event>>,
+) -> (impl Stream<Item = Result<Event>>, Arc<UsageTotal>) {
+    let usage = Arc::new(UsageTotal::default());
+    let usage_writer = usage.clone();
+    let events = events.inspect(move |event| {
+        if let Ok(event) = event {
+            apply_usage_event(&usage_writer, event);
+        }
+    });
+    (events, usage)
+}
+
+/// Like [`track_usage`], but also returns a future that resolves to the
+/// final [`Usage`] once a [`Event::MessageStop`] is observed, sparing
+/// callers from polling [`UsageTotal`] themselves.
+pub fn final_usage(
+    events: impl Stream<Item = Result<Event>>,
+) -> (
+    impl Stream<Item = Result<Event>>,
+    impl Future<Output = Result<Usage>>,
+) {
+    let (events, usage) = track_usage(events);
+    let (usage_tx, usage_rx) = oneshot::channel();
+    let mut usage_tx = Some(usage_tx);
+    let events = events.inspect(move |event| {
+        if matches!(event, Ok(Event::MessageStop)) {
+            if let Some(usage_tx) = usage_tx.take() {
+                let _ = usage_tx.send(usage.get());
+            }
+        }
+    });
+    let usage = async move {
+        usage_rx
+            .await
+            .map_err(|_| anyhow!("stream ended before a message_stop event was received"))
+    };
+    (events, usage)
+}
+
 fn api_error_to_err(
     ApiError {
         error_type,
@@ -221,20 +633,20 @@ fn api_error_to_err(
     anyhow!("API error. Type: '{error_type}', message: '{message}'",)
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Message {
     pub role: Role,
     pub content: Vec<Content>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum Role {
     User,
     Assistant,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type")]
 pub enum Content {
     #[serde(rename = "text")]
@@ -250,11 +662,34 @@ pub enum Content {
     #[serde(rename = "tool_result")]
     ToolResult {
         tool_use_id: String,
-        content: String,
+        #[serde(deserialize_with = "deserialize_tool_result_content")]
+        content: Vec<Content>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        is_error: Option<bool>,
     },
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+/// Anthropic accepts a tool result's `content` either as a bare string or as
+/// a list of content blocks (text and images); we always serialize the list
+/// form but still accept the string form for backward compatibility.
+fn deserialize_tool_result_content<'de, D>(deserializer: D) -> Result<Vec<Content>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum StringOrContent {
+        String(String),
+        Content(Vec<Content>),
+    }
+
+    Ok(match StringOrContent::deserialize(deserializer)? {
+        StringOrContent::String(text) => vec![Content::Text { text }],
+        StringOrContent::Content(content) => content,
+    })
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ImageSource {
     #[serde(rename = "type")]
     pub source_type: String,
@@ -262,14 +697,14 @@ pub struct ImageSource {
     pub data: String,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Tool {
     pub name: String,
     pub description: String,
     pub input_schema: serde_json::Value,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type", rename_all = "lowercase")]
 pub enum ToolChoice {
     Auto,
@@ -277,7 +712,7 @@ pub enum ToolChoice {
     Tool { name: String },
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Request {
     pub model: String,
     pub max_tokens: u32,
@@ -307,12 +742,12 @@ struct StreamingRequest {
     pub stream: bool,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Metadata {
     pub user_id: Option<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize)]
 pub struct Usage {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub input_tokens: Option<u32>,
@@ -380,3 +815,45 @@ pub struct ApiError {
     pub error_type: String,
     pub message: String,
 }
+
+impl ApiError {
+    pub fn kind(&self) -> ApiErrorKind {
+        ApiErrorKind::from_type(&self.error_type)
+    }
+}
+
+/// A typed classification of [`ApiError::error_type`], so callers can match
+/// on rate-limit vs. auth failures without string-matching the raw type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApiErrorKind {
+    InvalidRequest,
+    Authentication,
+    Permission,
+    NotFound,
+    RateLimit,
+    Overloaded,
+    Api,
+    Other,
+}
+
+impl ApiErrorKind {
+    fn from_type(error_type: &str) -> Self {
+        match error_type {
+            "invalid_request_error" => Self::InvalidRequest,
+            "authentication_error" => Self::Authentication,
+            "permission_error" => Self::Permission,
+            "not_found_error" => Self::NotFound,
+            "rate_limit_error" => Self::RateLimit,
+            "overloaded_error" => Self::Overloaded,
+            "api_error" => Self::Api,
+            _ => Self::Other,
+        }
+    }
+
+    /// Whether a request that failed with this error kind is worth retrying
+    /// with backoff. `invalid_request_error`, `authentication_error`, and
+    /// `permission_error` fail fast instead, since retrying can't help.
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, Self::Overloaded | Self::RateLimit | Self::Api)
+    }
+}
@@ -0,0 +1,77 @@
+use anyhow::{anyhow, Result};
+use futures::{stream::BoxStream, AsyncBufRead, AsyncBufReadExt, StreamExt};
+
+/// A single decoded Server-Sent-Events frame.
+///
+/// A frame is terminated by a blank line; if it carries more than one
+/// `data:` field, the fields are concatenated with `\n` as the SSE spec
+/// requires. Lines beginning with `:` are comments (often used as
+/// keepalives) and are ignored.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct SseEvent {
+    pub event: Option<String>,
+    pub data: String,
+    pub id: Option<String>,
+    pub retry: Option<u64>,
+}
+
+/// Decodes a byte stream into a stream of [`SseEvent`] frames.
+pub fn decode_stream(
+    reader: impl AsyncBufRead + Send + Unpin + 'static,
+) -> BoxStream<'static, Result<SseEvent>> {
+    let lines = reader.lines();
+    futures::stream::unfold(
+        (lines, SseEvent::default(), false),
+        |(mut lines, mut frame, mut has_data)| async move {
+            loop {
+                match lines.next().await {
+                    Some(Ok(line)) => {
+                        let line = line.strip_suffix('\r').unwrap_or(&line);
+                        if line.is_empty() {
+                            if has_data {
+                                return Some((Ok(frame), (lines, SseEvent::default(), false)));
+                            }
+                            // Blank line ending a frame with no data field: discard the
+                            // frame's other fields and keep reading.
+                            frame = SseEvent::default();
+                            continue;
+                        }
+                        if line.starts_with(':') {
+                            continue;
+                        }
+                        let (field, value) = match line.split_once(':') {
+                            Some((field, value)) => {
+                                (field, value.strip_prefix(' ').unwrap_or(value))
+                            }
+                            None => (line, ""),
+                        };
+                        match field {
+                            "event" => frame.event = Some(value.to_string()),
+                            "data" => {
+                                if has_data {
+                                    frame.data.push('\n');
+                                }
+                                frame.data.push_str(value);
+                                has_data = true;
+                            }
+                            "id" => frame.id = Some(value.to_string()),
+                            "retry" => frame.retry = value.parse().ok(),
+                            _ => {}
+                        }
+                    }
+                    Some(Err(error)) => {
+                        return Some((Err(anyhow!(error)), (lines, frame, has_data)))
+                    }
+                    None => {
+                        return if has_data {
+                            Some((Ok(frame), (lines, SseEvent::default(), false)))
+                        } else {
+                            None
+                        };
+                    }
+                }
+            }
+        },
+    )
+    .boxed()
+}